@@ -6,15 +6,15 @@ use bevy::{
     render::{
         mesh::{MeshVertexBufferLayout, PrimitiveTopology},
         render_resource::{
-            AsBindGroup, PolygonMode, RenderPipelineDescriptor, ShaderRef,
+            AsBindGroup, Face, PolygonMode, RenderPipelineDescriptor, ShaderRef, ShaderType,
             SpecializedMeshPipelineError,
         },
+        renderer::RenderDevice,
     },
 };
 
 use bevy::time::common_conditions::on_timer;
 // use bevy_inspector_egui::quick::WorldInspectorPlugin;
-// use bevy_mod_picking::{DefaultPickingPlugins, PickableBundle};
 use bevy_mod_reqwest::*;
 use bevy_panorbit_camera::*;
 use serde::Deserialize;
@@ -22,6 +22,7 @@ use std::time::Duration;
 
 const BLOCK_SPEED: f32 = 0.2;
 const TX_SPACING: f32 = 0.05;
+const CHAIN_LANE_SPACING: f32 = 4.0;
 
 #[derive(Deserialize)]
 struct TransactionResponse {
@@ -73,11 +74,276 @@ impl Default for Block {
     }
 }
 
+/// Ties a `Block`/`Transaction`/request entity to one of the configured chains.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+struct ChainId(u32);
+
+/// One chain being polled: where to fetch it from and where to render it.
+#[derive(Debug, Clone)]
+struct ChainSource {
+    chain_id: ChainId,
+    label: String,
+    endpoint: String,
+    // World-space X offset so each chain animates down its own lane.
+    lane_offset: f32,
+}
+
+/// RPC endpoint(s) to poll and how often. Chains are read from `BLOCKVIZ_CHAIN_<n>_*` env vars
+/// (or a single `BLOCKVIZ_RPC_URL` chain when none are set); the default poll interval is 2 seconds.
+#[derive(Resource, Debug, Clone)]
+struct RpcConfig {
+    poll_interval: Duration,
+    chains: Vec<ChainSource>,
+}
+
+/// Reads chain `index` from env vars; `None` once `_RPC_URL` is unset.
+fn chain_source_from_env(index: usize, lane_offset: f32) -> Option<ChainSource> {
+    let url = std::env::var(format!("BLOCKVIZ_CHAIN_{index}_RPC_URL")).ok()?;
+    let label = std::env::var(format!("BLOCKVIZ_CHAIN_{index}_LABEL"))
+        .unwrap_or_else(|_| format!("chain-{index}"));
+    let endpoint = match std::env::var(format!("BLOCKVIZ_CHAIN_{index}_API_KEY")) {
+        Ok(api_key) => format!("{}/{}", url.trim_end_matches('/'), api_key),
+        Err(_) => url,
+    };
+
+    Some(ChainSource {
+        chain_id: ChainId(index as u32),
+        label,
+        endpoint,
+        lane_offset,
+    })
+}
+
+impl Default for RpcConfig {
+    fn default() -> Self {
+        let mut chains: Vec<ChainSource> = std::iter::successors(Some(0usize), |i| Some(i + 1))
+            .map_while(|i| chain_source_from_env(i, i as f32 * CHAIN_LANE_SPACING))
+            .collect();
+
+        if chains.is_empty() {
+            chains.push(ChainSource {
+                chain_id: ChainId(0),
+                label: std::env::var("BLOCKVIZ_CHAIN_LABEL")
+                    .unwrap_or_else(|_| "ethereum-mainnet".into()),
+                endpoint: std::env::var("BLOCKVIZ_RPC_URL")
+                    .unwrap_or_else(|_| "https://eth.llamarpc.com".into()),
+                lane_offset: 0.0,
+            });
+        }
+
+        RpcConfig {
+            poll_interval: Duration::from_secs(2),
+            chains,
+        }
+    }
+}
+
+/// Eligible for click picking; `half_extents` is its world-space AABB radius.
+#[derive(Component, Debug)]
+struct Pickable {
+    half_extents: Vec3,
+}
+
+/// The `Block` entity under the last click, or the block of the clicked transaction, if any.
+#[derive(Resource, Default)]
+struct SelectedBlock(Option<Entity>);
+
+/// The `Transaction` entity nearest the camera under the last click, if any.
+#[derive(Resource, Default)]
+struct SelectedTx(Option<Entity>);
+
+/// Marks the UI text node showing the currently selected block/transaction.
+#[derive(Component)]
+struct InspectorPanelText;
+
+/// Insert on a `Block` entity to outline it (expanded back-face silhouette).
+#[derive(Component, Debug, Clone, Copy)]
+struct OutlineBundle {
+    color: Color,
+    width: f32,
+}
+
+impl Default for OutlineBundle {
+    fn default() -> Self {
+        OutlineBundle {
+            color: Color::WHITE,
+            width: 0.02,
+        }
+    }
+}
+
+/// Marks an outline mesh spawned by `sync_outlines`, so it can be despawned.
+#[derive(Component)]
+struct OutlineMesh;
+
+/// Colors and threshold for the selection and congestion outline sources.
+#[derive(Resource)]
+struct OutlineConfig {
+    selection_color: Color,
+    congestion_color: Color,
+    congestion_threshold: f32,
+    width: f32,
+}
+
+impl Default for OutlineConfig {
+    fn default() -> Self {
+        OutlineConfig {
+            selection_color: Color::rgb_u8(255, 221, 87),
+            congestion_color: Color::rgb_u8(220, 60, 60),
+            congestion_threshold: 0.9,
+            width: 0.02,
+        }
+    }
+}
+
+const WIREFRAME_COLOR: Color = Color::rgb_u8(230, 230, 230);
+
+/// Global wireframe toggle flipped by the `W` key.
+#[derive(Resource, Default)]
+struct WireframeModeEnabled(bool);
+
+/// Opt-in marker: the entity's `StandardMaterial` cube is wireframe regardless of the global toggle.
+/// Instanced transaction cubes follow only the global toggle; the marker does not affect them.
+#[derive(Component, Debug, Default)]
+struct Wireframe;
+
+/// The original material stashed while swapped out for `WireframeMaterial`.
+#[derive(Component)]
+struct WireframeSwap {
+    standard_material: Handle<StandardMaterial>,
+}
+
+/// `W`-key wireframe toggle for `Block`/`Transaction` cubes.
+struct WireframeModePlugin;
+
+impl Plugin for WireframeModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WireframeModeEnabled>()
+            .add_plugins(MaterialPlugin::<WireframeMaterial>::default())
+            .add_systems(
+                Update,
+                (
+                    toggle_wireframe_mode,
+                    toggle_selected_wireframe_marker,
+                    apply_wireframe_mode,
+                    apply_wireframe_mode_to_instanced_cubes,
+                ),
+            );
+    }
+}
+
+fn toggle_wireframe_mode(keyboard: Res<Input<KeyCode>>, mut mode: ResMut<WireframeModeEnabled>) {
+    if keyboard.just_pressed(KeyCode::W) {
+        mode.0 = !mode.0;
+    }
+}
+
+/// `T` toggles the `Wireframe` opt-in marker on whichever block/transaction is selected.
+fn toggle_selected_wireframe_marker(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    selected_block: Res<SelectedBlock>,
+    selected_tx: Res<SelectedTx>,
+    wireframe_marked: Query<(), With<Wireframe>>,
+) {
+    if !keyboard.just_pressed(KeyCode::T) {
+        return;
+    }
+
+    let Some(entity) = selected_tx.0.or(selected_block.0) else {
+        return;
+    };
+
+    if wireframe_marked.get(entity).is_ok() {
+        commands.entity(entity).remove::<Wireframe>();
+    } else {
+        commands.entity(entity).insert(Wireframe);
+    }
+}
+
+/// Swaps `Block`/`Transaction` cubes between `StandardMaterial` and `WireframeMaterial`.
+fn apply_wireframe_mode(
+    mut commands: Commands,
+    mode: Res<WireframeModeEnabled>,
+    mut wireframe_materials: ResMut<Assets<WireframeMaterial>>,
+    renderable: Query<
+        (
+            Entity,
+            Option<&Handle<StandardMaterial>>,
+            Option<&WireframeSwap>,
+            Option<&Wireframe>,
+        ),
+        Or<(With<Block>, With<Transaction>)>,
+    >,
+) {
+    for (entity, standard_material, swap, wireframe_marker) in renderable.iter() {
+        let should_be_wireframe = mode.0 || wireframe_marker.is_some();
+
+        match (should_be_wireframe, standard_material, swap) {
+            (true, Some(material), None) => {
+                commands
+                    .entity(entity)
+                    .remove::<Handle<StandardMaterial>>()
+                    .insert(wireframe_materials.add(WireframeMaterial {
+                        color: WIREFRAME_COLOR,
+                    }))
+                    .insert(WireframeSwap {
+                        standard_material: material.clone(),
+                    });
+            }
+            (false, None, Some(swap)) => {
+                commands
+                    .entity(entity)
+                    .remove::<Handle<WireframeMaterial>>()
+                    .remove::<WireframeSwap>()
+                    .insert(swap.standard_material.clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Instanced transaction cubes have no `Handle<StandardMaterial>` to swap, so flip their own `wireframe` flag instead.
+fn apply_wireframe_mode_to_instanced_cubes(
+    mode: Res<WireframeModeEnabled>,
+    handles: Query<&Handle<InstancedCubeMaterial>>,
+    mut instanced_materials: ResMut<Assets<InstancedCubeMaterial>>,
+) {
+    if !mode.is_changed() {
+        return;
+    }
+
+    for handle in handles.iter() {
+        if let Some(material) = instanced_materials.get_mut(handle) {
+            material.wireframe = mode.0;
+        }
+    }
+}
+
+/// Whether the render device supports storage buffers, detected once at startup.
+#[derive(Resource)]
+struct InstancingSupport {
+    storage_buffers_supported: bool,
+}
+
+fn detect_instancing_support(render_device: Res<RenderDevice>, mut commands: Commands) {
+    let storage_buffers_supported = render_device.limits().max_storage_buffers_per_shader_stage > 0;
+    commands.insert_resource(InstancingSupport {
+        storage_buffers_supported,
+    });
+}
+
 fn main() {
     App::new()
         .insert_resource(ClearColor(Color::rgb_u8(47, 72, 88)))
         .register_type::<Block>()
-        .add_systems(Startup, setup)
+        .register_type::<ChainId>()
+        .init_resource::<SelectedBlock>()
+        .init_resource::<SelectedTx>()
+        .init_resource::<OutlineConfig>()
+        .init_resource::<RpcConfig>()
+        .add_systems(Startup, (setup, detect_instancing_support))
         .add_plugins(PanOrbitCameraPlugin)
         // .add_plugins(DefaultPickingPlugins)
         .add_plugins((
@@ -85,6 +351,10 @@ fn main() {
             // WorldInspectorPlugin::default(),
             ReqwestPlugin,
             MaterialPlugin::<LineMaterial>::default(),
+            MaterialPlugin::<InstancedCubeMaterial>::default(),
+            MaterialPlugin::<OutlineMaterial>::default(),
+            MaterialPlugin::<GasGradientMaterial>::default(),
+            WireframeModePlugin,
         ))
         .insert_resource(AmbientLight {
             color: Color::WHITE,
@@ -93,11 +363,21 @@ fn main() {
         .add_systems(
             Update,
             (
-                send_requests.run_if(on_timer(Duration::from_secs(2))),
+                send_requests,
                 handle_responses.run_if(on_timer(Duration::from_secs(1))),
             ),
         )
         .add_systems(Update, block_movement)
+        .add_systems(
+            Update,
+            (
+                pick_on_click,
+                update_inspector_panel,
+                apply_selection_and_congestion_outlines,
+                sync_outlines,
+            )
+                .chain(),
+        )
         .run();
 }
 
@@ -142,6 +422,33 @@ fn setup(
         },
         PanOrbitCamera::default(),
     ));
+
+    // Inspector panel: its text stays empty until a block or transaction is picked.
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                left: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+                InspectorPanelText,
+            ));
+        });
 }
 
 fn block_movement(mut enemy_query: Query<&mut Transform, With<Block>>, time: Res<Time>) {
@@ -151,45 +458,91 @@ fn block_movement(mut enemy_query: Query<&mut Transform, With<Block>>, time: Res
     }
 }
 
-fn send_requests(mut commands: Commands, reqwest: Res<ReqwestClient>) {
-    let url = "https://mainnet.infura.io/v3/6fffe7dc6c6c42459d5443592d3c3afc";
+/// Polls every configured chain on `config.poll_interval`, tagging each request with its `ChainId`.
+fn send_requests(
+    mut commands: Commands,
+    reqwest: Res<ReqwestClient>,
+    config: Res<RpcConfig>,
+    time: Res<Time>,
+    mut timer: Local<Option<Timer>>,
+) {
+    let timer = timer.get_or_insert_with(|| Timer::new(config.poll_interval, TimerMode::Repeating));
+    if !timer.tick(time.delta()).just_finished() {
+        return;
+    }
 
-    let req = reqwest
-        .0
-        .post(url)
-        .json(&serde_json::json!({
+    for chain in &config.chains {
+        let req = reqwest.0.post(&chain.endpoint).json(&serde_json::json!({
             "jsonrpc": "2.0",
             "method": "eth_getBlockByNumber",
             "params": ["latest", true],
             "id": 1,
-        }))
-        .build()
-        .unwrap();
-    let req = ReqwestRequest::new(req);
-    commands.spawn(req);
+        }));
+
+        match req.build() {
+            Ok(req) => {
+                commands.spawn((ReqwestRequest::new(req), chain.chain_id));
+            }
+            Err(err) => {
+                eprintln!(
+                    "skipping chain '{}': failed to build request: {err}",
+                    chain.label
+                );
+            }
+        }
+    }
 }
 
 fn handle_responses(
     mut commands: Commands,
-    results: Query<(Entity, &ReqwestBytesResult)>,
-    query: Query<&Block>,
+    results: Query<(Entity, &ReqwestBytesResult, &ChainId)>,
+    query: Query<(&Block, &ChainId)>,
+    config: Res<RpcConfig>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut line_materials: ResMut<Assets<LineMaterial>>,
+    mut instanced_materials: ResMut<Assets<InstancedCubeMaterial>>,
+    mut gas_gradient_materials: ResMut<Assets<GasGradientMaterial>>,
+    instancing: Res<InstancingSupport>,
+    wireframe_mode: Res<WireframeModeEnabled>,
 ) {
-    for (e, res) in results.iter() {
-        let a: Response = serde_json::from_slice(res.as_ref().unwrap()).unwrap();
+    for (e, res, chain_id) in results.iter() {
+        let chain = config
+            .chains
+            .iter()
+            .find(|chain| chain.chain_id == *chain_id);
+        let lane_offset = chain.map(|chain| chain.lane_offset).unwrap_or(0.0);
+        let chain_label = chain.map(|chain| chain.label.as_str()).unwrap_or("unknown");
+
+        let bytes = match res.as_ref() {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                eprintln!("skipping response from '{chain_label}': request failed: {err}");
+                commands.entity(e).despawn_recursive();
+                continue;
+            }
+        };
+        let a: Response = match serde_json::from_slice(bytes) {
+            Ok(response) => response,
+            Err(err) => {
+                eprintln!("skipping response from '{chain_label}': failed to parse JSON-RPC response: {err}");
+                commands.entity(e).despawn_recursive();
+                continue;
+            }
+        };
         let mut previous_position = Vec3::ZERO;
 
         if let Ok(number) = u64::from_str_radix(&a.result.number[2..], 16) {
             if let Ok(gas_limit) = u64::from_str_radix(&a.result.gas_limit[2..], 16) {
                 if let Ok(gas_used) = u64::from_str_radix(&a.result.gas_used[2..], 16) {
-                    let block_exists = query.iter().any(|block| block.number == number);
+                    let block_exists = query
+                        .iter()
+                        .any(|(block, id)| block.number == number && id == chain_id);
 
                     if block_exists {
                         println!("Already have this block");
                     } else {
-                        println!("spawning new block {}", number);
+                        println!("spawning new block {} on {}", number, chain_label);
                         let ratio = gas_used as f32 / gas_limit as f32;
 
                         let new_height = 1.0 * ratio;
@@ -202,19 +555,23 @@ fn handle_responses(
                                 gas_limit,
                                 gas_used,
                             })
+                            .insert(*chain_id)
                             .insert(PbrBundle {
                                 mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
                                 material: materials.add(Color::rgb_u8(51, 102, 153).into()),
-                                transform: Transform::from_xyz(0.0, 0.5, 0.0),
+                                transform: Transform::from_xyz(lane_offset, 0.5, 0.0),
                                 ..default()
                             })
-                            // .insert(PickableBundle::default())
+                            .insert(Pickable {
+                                half_extents: Vec3::splat(0.5),
+                            })
                             .with_children(|parent| {
-                                parent.spawn(PbrBundle {
+                                parent.spawn(MaterialMeshBundle {
                                     mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
-                                    material: materials.add(StandardMaterial {
-                                        base_color: Color::rgb_u8(134, 187, 216), // 4. Put something bright in a dark environment to see the effect
-                                        ..default()
+                                    material: gas_gradient_materials.add(GasGradientMaterial {
+                                        ratio,
+                                        low_color: Color::rgb_u8(70, 130, 200),
+                                        high_color: Color::rgb_u8(220, 60, 60),
                                     }),
                                     // transform: Transform::from_xyz(0.0, center_translation, 0.0).with_scale(Vec3::new(0.99, ratio, 0.99)),
                                     transform: Transform::from_xyz(0.0, center_translation, 0.0)
@@ -223,6 +580,10 @@ fn handle_responses(
                                 });
 
                                 let mut offset = 1.0 / 2.0 - TX_SPACING;
+                                let unit_cube_mesh =
+                                    meshes.add(Mesh::from(shape::Cube { size: 1.0 }));
+                                let mut tx_instances =
+                                    Vec::with_capacity(a.result.transactions.len());
                                 // spawn cubes for each transaction spaced vertically
                                 for (i, t) in a.result.transactions.iter().enumerate() {
                                     let gas = u64::from_str_radix(&t.gas[2..], 16).unwrap();
@@ -231,16 +592,66 @@ fn handle_responses(
                                     let tx_translation = offset - (tx_ratio / 2.0);
                                     let current_position = Vec3::new(0.0, tx_translation, 0.0);
                                     let limited_value = f32::min(f32::max(tx_ratio, 0.05), 1.0);
-                                    parent.spawn(PbrBundle {
-                                        mesh: meshes.add(Mesh::from(shape::Cube { size: ratio })),
-                                        material: materials.add(StandardMaterial {
-                                            base_color: Color::rgb_u8(134, 187, 216), // 4. Put something bright in a dark environment to see the effect
-                                            ..default()
-                                        }),
-                                        transform: Transform::from_xyz(0.0, tx_translation, 0.0)
-                                            .with_scale(limited_value * Vec3::new(1.0, 1.0, 1.0)),
-                                        ..default()
-                                    });
+                                    let tx_half_extents = Vec3::splat(0.5 * ratio * limited_value);
+                                    let tx_component = Transaction {
+                                        block_number: number,
+                                        gas,
+                                        index,
+                                    };
+
+                                    if instancing.storage_buffers_supported {
+                                        tx_instances.push(InstanceData {
+                                            offset: current_position,
+                                            scale: limited_value * ratio,
+                                            color: Vec4::new(
+                                                134.0 / 255.0,
+                                                187.0 / 255.0,
+                                                216.0 / 255.0,
+                                                1.0,
+                                            ),
+                                        });
+
+                                        // The instanced draw call spawned after this loop has no per-transaction
+                                        // entity to pick, so spawn an invisible proxy with the
+                                        // same bounds purely for ray intersection.
+                                        parent.spawn((
+                                            tx_component,
+                                            *chain_id,
+                                            TransformBundle::from_transform(Transform::from_xyz(
+                                                0.0,
+                                                tx_translation,
+                                                0.0,
+                                            )),
+                                            Pickable {
+                                                half_extents: tx_half_extents,
+                                            },
+                                        ));
+                                    } else {
+                                        parent.spawn((
+                                            PbrBundle {
+                                                mesh: meshes
+                                                    .add(Mesh::from(shape::Cube { size: ratio })),
+                                                material: materials.add(StandardMaterial {
+                                                    base_color: Color::rgb_u8(134, 187, 216), // 4. Put something bright in a dark environment to see the effect
+                                                    ..default()
+                                                }),
+                                                transform: Transform::from_xyz(
+                                                    0.0,
+                                                    tx_translation,
+                                                    0.0,
+                                                )
+                                                .with_scale(
+                                                    limited_value * Vec3::new(1.0, 1.0, 1.0),
+                                                ),
+                                                ..default()
+                                            },
+                                            tx_component,
+                                            *chain_id,
+                                            Pickable {
+                                                half_extents: tx_half_extents,
+                                            },
+                                        ));
+                                    }
 
                                     parent.spawn(MaterialMeshBundle {
                                         mesh: meshes.add(Mesh::from(LineList {
@@ -255,6 +666,20 @@ fn handle_responses(
                                     offset -= tx_ratio / 2.0 - TX_SPACING;
                                     previous_position = current_position;
                                 }
+
+                                // One draw call for every transaction cube in the block instead
+                                // of one PbrBundle each.
+                                if instancing.storage_buffers_supported && !tx_instances.is_empty()
+                                {
+                                    parent.spawn(MaterialMeshBundle {
+                                        mesh: unit_cube_mesh,
+                                        material: instanced_materials.add(InstancedCubeMaterial {
+                                            instances: tx_instances,
+                                            wireframe: wireframe_mode.0,
+                                        }),
+                                        ..default()
+                                    });
+                                }
                             });
                     }
                 }
@@ -289,6 +714,136 @@ impl Material for LineMaterial {
     }
 }
 
+/// Per-instance data read by `instanced_cube.wgsl` from a storage buffer.
+#[derive(Debug, Clone, Copy, ShaderType)]
+struct InstanceData {
+    offset: Vec3,
+    scale: f32,
+    color: Vec4,
+}
+
+/// One draw call for every transaction cube in a block, via a shared unit mesh.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+#[bind_group_data(InstancedCubeMaterialKey)]
+struct InstancedCubeMaterial {
+    #[storage(0, read_only)]
+    instances: Vec<InstanceData>,
+    wireframe: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct InstancedCubeMaterialKey {
+    wireframe: bool,
+}
+
+impl From<&InstancedCubeMaterial> for InstancedCubeMaterialKey {
+    fn from(material: &InstancedCubeMaterial) -> Self {
+        InstancedCubeMaterialKey {
+            wireframe: material.wireframe,
+        }
+    }
+}
+
+impl Material for InstancedCubeMaterial {
+    fn vertex_shader() -> ShaderRef {
+        "shaders/instanced_cube.wgsl".into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        "shaders/instanced_cube.wgsl".into()
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        if key.bind_group_data.wireframe {
+            descriptor.primitive.polygon_mode = PolygonMode::Line;
+        }
+        Ok(())
+    }
+}
+
+/// Colors a block's gas-utilization fill cube from cool (idle) to hot (full).
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+struct GasGradientMaterial {
+    #[uniform(0)]
+    ratio: f32,
+    #[uniform(0)]
+    low_color: Color,
+    #[uniform(0)]
+    high_color: Color,
+}
+
+impl Material for GasGradientMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/gas_gradient_material.wgsl".into()
+    }
+}
+
+/// Reuses `LineMaterial`'s shader, with its own color.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+struct WireframeMaterial {
+    #[uniform(0)]
+    color: Color,
+}
+
+impl Material for WireframeMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/line_material.wgsl".into()
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        descriptor.primitive.polygon_mode = PolygonMode::Line;
+        Ok(())
+    }
+}
+
+/// Renders the expanded back-face silhouette used by `OutlineBundle`.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+struct OutlineMaterial {
+    #[uniform(0)]
+    color: Color,
+    #[uniform(0)]
+    width: f32,
+}
+
+impl Material for OutlineMaterial {
+    fn vertex_shader() -> ShaderRef {
+        "shaders/outline_material.wgsl".into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        "shaders/outline_material.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Opaque
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // Render only the back faces, expanded along their normals, so the
+        // silhouette shows up exactly where it pokes past the original mesh.
+        descriptor.primitive.cull_mode = Some(Face::Front);
+        if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+            depth_stencil.depth_write_enabled = false;
+        }
+        Ok(())
+    }
+}
+
 /// A list of lines with a start and end position
 #[derive(Debug, Clone)]
 pub struct LineList {
@@ -307,6 +862,206 @@ impl From<LineList> for Mesh {
     }
 }
 
+/// On left click, selects the nearest `Transaction` under the cursor (and its block);
+/// if no transaction is hit, selects the nearest `Block` instead.
+fn pick_on_click(
+    mouse_button: Res<Input<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    pickable_blocks: Query<(Entity, &GlobalTransform, &Pickable, &Block)>,
+    pickable_txs: Query<(Entity, &GlobalTransform, &Pickable, &Transaction)>,
+    mut selected_block: ResMut<SelectedBlock>,
+    mut selected_tx: ResMut<SelectedTx>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let Some(cursor_position) = window.cursor_position() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+    let Some(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
+        return;
+    };
+
+    let nearest_tx = pickable_txs
+        .iter()
+        .filter_map(|(entity, transform, pickable, tx)| {
+            ray_aabb_distance(&ray, transform.translation(), pickable.half_extents)
+                .map(|distance| (entity, tx.block_number, distance))
+        })
+        .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b));
+
+    if let Some((entity, block_number, _)) = nearest_tx {
+        selected_tx.0 = Some(entity);
+        selected_block.0 = pickable_blocks
+            .iter()
+            .find(|(_, _, _, block)| block.number == block_number)
+            .map(|(entity, _, _, _)| entity);
+        return;
+    }
+
+    let nearest_block = pickable_blocks
+        .iter()
+        .filter_map(|(entity, transform, pickable, _)| {
+            ray_aabb_distance(&ray, transform.translation(), pickable.half_extents)
+                .map(|distance| (entity, distance))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    selected_tx.0 = None;
+    selected_block.0 = nearest_block.map(|(entity, _)| entity);
+}
+
+/// Ray/AABB intersection via the slab method; `None` if the ray misses.
+fn ray_aabb_distance(ray: &Ray, center: Vec3, half_extents: Vec3) -> Option<f32> {
+    let min = center - half_extents;
+    let max = center + half_extents;
+
+    let mut t_min = 0.0_f32;
+    let mut t_max = f32::MAX;
+
+    for axis in 0..3 {
+        let origin = ray.origin[axis];
+        let direction = ray.direction[axis];
+
+        if direction.abs() < f32::EPSILON {
+            if origin < min[axis] || origin > max[axis] {
+                return None;
+            }
+            continue;
+        }
+
+        let inv_direction = 1.0 / direction;
+        let mut t1 = (min[axis] - origin) * inv_direction;
+        let mut t2 = (max[axis] - origin) * inv_direction;
+        if t1 > t2 {
+            std::mem::swap(&mut t1, &mut t2);
+        }
+
+        t_min = t_min.max(t1);
+        t_max = t_max.min(t2);
+        if t_min > t_max {
+            return None;
+        }
+    }
+
+    Some(t_min)
+}
+
+/// Shows the selected block/transaction's fields, or clears the panel's text.
+fn update_inspector_panel(
+    selected_block: Res<SelectedBlock>,
+    selected_tx: Res<SelectedTx>,
+    blocks: Query<&Block>,
+    txs: Query<&Transaction>,
+    mut panel_text: Query<&mut Text, With<InspectorPanelText>>,
+) {
+    let Ok(mut text) = panel_text.get_single_mut() else {
+        return;
+    };
+
+    if let Some(tx) = selected_tx.0.and_then(|entity| txs.get(entity).ok()) {
+        let utilization = blocks
+            .iter()
+            .find(|block| block.number == tx.block_number)
+            .map(|block| tx.gas as f32 / block.gas_limit as f32 * 100.0)
+            .unwrap_or(0.0);
+        text.sections[0].value = format!(
+            "Transaction\nblock:  {}\nindex:  {}\ngas:    {}\nutil:   {:.1}%",
+            tx.block_number, tx.index, tx.gas, utilization
+        );
+    } else if let Some(block) = selected_block.0.and_then(|entity| blocks.get(entity).ok()) {
+        let utilization = block.gas_used as f32 / block.gas_limit as f32 * 100.0;
+        text.sections[0].value = format!(
+            "Block\nnumber:    {}\ngas_limit: {}\ngas_used:  {}\nutil:      {:.1}%",
+            block.number, block.gas_limit, block.gas_used, utilization
+        );
+    } else {
+        text.sections[0].value.clear();
+    }
+}
+
+/// Outlines the selected block, or any block at/above the congestion threshold.
+fn apply_selection_and_congestion_outlines(
+    mut commands: Commands,
+    selected_block: Res<SelectedBlock>,
+    config: Res<OutlineConfig>,
+    blocks: Query<(Entity, &Block, Option<&OutlineBundle>)>,
+) {
+    for (entity, block, existing) in blocks.iter() {
+        let ratio = block.gas_used as f32 / block.gas_limit as f32;
+        let desired_color = if selected_block.0 == Some(entity) {
+            Some(config.selection_color)
+        } else if ratio >= config.congestion_threshold {
+            Some(config.congestion_color)
+        } else {
+            None
+        };
+
+        match (desired_color, existing) {
+            (Some(color), existing) if existing.map(|o| o.color) != Some(color) => {
+                commands.entity(entity).insert(OutlineBundle {
+                    color,
+                    width: config.width,
+                });
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<OutlineBundle>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Keeps each entity's outline mesh in sync with its `OutlineBundle`.
+fn sync_outlines(
+    mut commands: Commands,
+    changed_outlines: Query<(Entity, &OutlineBundle, &Handle<Mesh>), Changed<OutlineBundle>>,
+    mut removed_outlines: RemovedComponents<OutlineBundle>,
+    children: Query<&Children>,
+    outline_meshes: Query<Entity, With<OutlineMesh>>,
+    mut outline_materials: ResMut<Assets<OutlineMaterial>>,
+) {
+    let despawn_outline_children = |commands: &mut Commands, entity: Entity| {
+        if let Ok(kids) = children.get(entity) {
+            for &child in kids.iter() {
+                if outline_meshes.get(child).is_ok() {
+                    commands.entity(child).despawn_recursive();
+                }
+            }
+        }
+    };
+
+    for entity in removed_outlines.read() {
+        despawn_outline_children(&mut commands, entity);
+    }
+
+    for (entity, outline, mesh) in changed_outlines.iter() {
+        despawn_outline_children(&mut commands, entity);
+
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                MaterialMeshBundle {
+                    mesh: mesh.clone(),
+                    material: outline_materials.add(OutlineMaterial {
+                        color: outline.color,
+                        width: outline.width,
+                    }),
+                    ..default()
+                },
+                OutlineMesh,
+            ));
+        });
+    }
+}
+
 /// A list of points that will have a line drawn between each consecutive points
 #[derive(Debug, Clone)]
 pub struct LineStrip {